@@ -2,10 +2,119 @@ use num_bigint::{BigInt, BigUint, ToBigInt};
 use num_traits::{One, Zero};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use rayon::prelude::*;
 
 const BASE: u32 = 10;
 const MAX_ITERATIONS: usize = 10000;
 
+/// Check whether a sequence of digits reads the same forwards and backwards.
+#[inline(always)]
+fn is_palindrome(digits: &[u8]) -> bool {
+    digits.iter().eq(digits.iter().rev())
+}
+
+/// Validate a user-supplied radix before it reaches `to_radix_le`/`from_radix_be`,
+/// which panic rather than error for `base < 2`.
+fn validate_base(base: u32) -> PyResult<u32> {
+    if base < 2 {
+        Err(PyValueError::new_err(format!(
+            "base must be at least 2, got {base}"
+        )))
+    } else {
+        Ok(base)
+    }
+}
+
+/// The result of a single reverse-and-add step: either `current` is accepted as
+/// the terminal palindrome, or the routine must continue with the next term.
+enum ReverseAddStep {
+    Accepted(BigUint),
+    Continue(BigUint),
+}
+
+/// Perform one reverse-and-add step, the shared core of [`reverse_and_add_search`],
+/// [`reverse_and_add_path`], and `ReverseAddIterator::__next__`.
+///
+/// When `skip_check` is set, `current` is never accepted as the answer even if it
+/// is already a palindrome - this is how `require_step` forces at least one
+/// reverse-and-add before a palindromic seed can be accepted.
+fn reverse_and_add_step(current: BigUint, base: u32, skip_check: bool) -> ReverseAddStep {
+    let digits = current.to_radix_le(base);
+
+    if !skip_check && is_palindrome(&digits) {
+        return ReverseAddStep::Accepted(current);
+    }
+
+    ReverseAddStep::Continue(current + BigUint::from_radix_be(&digits, base).unwrap())
+}
+
+/// Run the reverse-and-add routine, returning the first palindrome reached.
+///
+/// This is the core of [`find_lychrel_palindrome`], factored out so the batch
+/// scanning functions can reuse it without going through the `PyResult`
+/// conversion or paying for a Python call per candidate.
+///
+/// When `require_step` is set, the seed itself is never accepted as the answer:
+/// at least one reverse-and-add step is taken before the palindrome check begins.
+/// This matches the standard Lychrel definition, under which some palindromes
+/// (the first being 4994) are themselves Lychrel numbers.
+///
+/// Returns `None` if no palindrome is found within `max_iterations` steps.
+fn reverse_and_add_search(
+    number: BigUint,
+    max_iterations: usize,
+    base: u32,
+    require_step: bool,
+) -> Option<(BigUint, usize)> {
+    let mut next = number;
+    let mut skip_seed_check = require_step;
+
+    for iterations in 0..max_iterations {
+        match reverse_and_add_step(next, base, skip_seed_check) {
+            ReverseAddStep::Accepted(palindrome) => return Some((palindrome, iterations)),
+            ReverseAddStep::Continue(value) => next = value,
+        }
+        skip_seed_check = false;
+    }
+
+    None
+}
+
+/// Run the reverse-and-add routine, returning the whole path to the palindrome.
+///
+/// Shares the same stepping logic as [`reverse_and_add_search`], but keeps every
+/// intermediate term instead of discarding them, since callers of
+/// [`reverse_and_add_sequence`] want to inspect the full trajectory.
+///
+/// Like [`reverse_and_add_search`], `require_step` forces at least one
+/// reverse-and-add step before a palindromic seed is accepted, so this shares
+/// the same classification convention.
+///
+/// Returns `None` if no palindrome is found within `max_iterations` steps.
+fn reverse_and_add_path(
+    number: BigUint,
+    max_iterations: usize,
+    base: u32,
+    require_step: bool,
+) -> Option<Vec<BigUint>> {
+    let mut next = number.clone();
+    let mut path = vec![number];
+    let mut skip_seed_check = require_step;
+
+    for _ in 0..max_iterations {
+        match reverse_and_add_step(next, base, skip_seed_check) {
+            ReverseAddStep::Accepted(_) => return Some(path),
+            ReverseAddStep::Continue(value) => {
+                path.push(value.clone());
+                next = value;
+            }
+        }
+        skip_seed_check = false;
+    }
+
+    None
+}
+
 /// Find the first palindrome produced by the reverse-and-add routine.
 ///
 /// This function implements the reverse-and-add algorithm used to test for Lychrel numbers.
@@ -16,17 +125,22 @@ const MAX_ITERATIONS: usize = 10000;
 ///
 /// * `number` - The starting number to test (any non-negative integer)
 /// * `max_iterations` - Maximum number of iterations to try before giving up (default: 10000)
+/// * `base` - The number base to operate in (default: 10)
+/// * `require_step` - Whether a palindromic seed must still take one reverse-and-add
+///   step before being accepted (default: `True`, the Euler 55 convention). When
+///   `True`, palindromic seeds such as 4994 are not trivially accepted as
+///   0-iteration palindromes.
 ///
 /// # Returns
 ///
 /// Returns a tuple `(palindrome, iterations)` where:
 /// * `palindrome` - The first palindrome found in the sequence
-/// * `iterations` - The number of iterations needed to reach the palindrome (0 if input is already a palindrome)
+/// * `iterations` - The number of iterations needed to reach the palindrome (0 if input is already a palindrome and `require_step` is `False`)
 ///
 /// # Errors
 ///
 /// Returns a `ValueError` if no palindrome is found within `max_iterations` steps,
-/// suggesting the number might be a Lychrel candidate.
+/// suggesting the number might be a Lychrel candidate, or if `base` is less than 2.
 ///
 /// # Examples
 ///
@@ -48,32 +162,32 @@ const MAX_ITERATIONS: usize = 10000;
 ///     lychrel.find_lychrel_palindrome(196, max_iterations=100)
 /// except ValueError:
 ///     print("No palindrome found - likely a Lychrel candidate")
+///
+/// # Other bases are supported too
+/// lychrel.find_lychrel_palindrome(10, base=2)
+///
+/// # 4994 is itself a palindrome, but under the default require_step=True it must
+/// # still take a step first - and, being a suspected Lychrel seed, never reaches
+/// # a new palindrome
+/// try:
+///     lychrel.find_lychrel_palindrome(4994, max_iterations=100)
+/// except ValueError:
+///     print("No palindrome found - likely a Lychrel candidate")
 /// ```
 #[pyfunction]
-#[pyo3(signature = (number, max_iterations=None))]
+#[pyo3(signature = (number, max_iterations=None, base=None, require_step=None))]
 fn find_lychrel_palindrome(
     number: BigUint,
     max_iterations: Option<usize>,
+    base: Option<u32>,
+    require_step: Option<bool>,
 ) -> PyResult<(BigUint, usize)> {
     let max_iterations = max_iterations.unwrap_or(MAX_ITERATIONS);
-    let mut next: BigUint = number;
-
-    for iterations in 0..max_iterations {
-        let base10_representation = next.to_radix_le(BASE);
-
-        // Check whether the decimal representation is palindrome
-        if base10_representation
-            .iter()
-            .eq(base10_representation.iter().rev())
-        {
-            return Ok((next, iterations));
-        }
+    let base = validate_base(base.unwrap_or(BASE))?;
+    let require_step = require_step.unwrap_or(true);
 
-        // Reverse and add
-        next += BigUint::from_radix_be(&base10_representation, BASE).unwrap();
-    }
-
-    Err(PyValueError::new_err("Maximum iterations reached"))
+    reverse_and_add_search(number, max_iterations, base, require_step)
+        .ok_or_else(|| PyValueError::new_err("Maximum iterations reached"))
 }
 
 /// Check whether a number is a potential Lychrel number.
@@ -87,6 +201,11 @@ fn find_lychrel_palindrome(
 /// * `number` - The number to test for Lychrel candidacy
 /// * `max_iterations` - Maximum iterations to try (default: 10000). If no palindrome is
 ///   found within this limit, the number is considered a Lychrel candidate.
+/// * `base` - The number base to operate in (default: 10)
+/// * `require_step` - Whether a palindromic seed must still take one reverse-and-add
+///   step before being accepted (default: `True`, the Euler 55 convention). With the
+///   default, a palindromic seed like 4994 is classified by what it does on the next
+///   step rather than trivially returning `False`.
 ///
 /// # Returns
 ///
@@ -110,16 +229,336 @@ fn find_lychrel_palindrome(
 /// # You can adjust the iteration limit
 /// # This might return True if the number needs more iterations
 /// lychrel.is_lychrel_candidate(197, max_iterations=5)
+///
+/// # 4994 is a palindrome, but is still a Lychrel number under the Euler 55 convention
+/// assert lychrel.is_lychrel_candidate(4994, max_iterations=100) == True
 /// ```
 ///
+/// # Errors
+///
+/// Returns a `ValueError` if `base` is less than 2.
+///
 /// # Note
 ///
 /// This function returns `true` for suspected Lychrel candidates, but cannot prove
-/// a number is truly a Lychrel number (which would require infinite iterations).
+/// a number is truly a Lychrel number (which would require infinite iterations), in
+/// any base. In some bases Lychrel numbers are proven to exist, such as base 2
+/// (10110 is the smallest proven base-2 Lychrel number); there too this function
+/// only reports whether a palindrome appears within `max_iterations` steps.
+#[pyfunction]
+#[pyo3(signature = (number, max_iterations=None, base=None, require_step=None))]
+fn is_lychrel_candidate(
+    number: BigUint,
+    max_iterations: Option<usize>,
+    base: Option<u32>,
+    require_step: Option<bool>,
+) -> PyResult<bool> {
+    let base = validate_base(base.unwrap_or(BASE))?;
+
+    Ok(find_lychrel_palindrome(number, max_iterations, Some(base), require_step).is_err())
+}
+
+/// Sweep an interval and collect every suspected Lychrel number it contains.
+///
+/// This runs the same reverse-and-add routine as [`find_lychrel_palindrome`] over
+/// every integer in `[start, end)`, splitting the work across all available cores
+/// with `rayon`. It exists so batch workloads like "all suspected Lychrel numbers
+/// between 1 and 100000" don't need a Python loop calling `is_lychrel_candidate`
+/// one number at a time.
+///
+/// # Arguments
+///
+/// * `start` - Start of the interval (inclusive)
+/// * `end` - End of the interval (exclusive)
+/// * `max_iterations` - Maximum iterations to try per candidate (default: 10000)
+/// * `base` - The number base to use (default: 10)
+/// * `require_step` - Whether a palindromic seed must still take one reverse-and-add
+///   step before being accepted (default: `True`, the Euler 55 convention, matching
+///   [`find_lychrel_palindrome`])
+///
+/// # Returns
+///
+/// A list of the candidates in `[start, end)` that fail to reach a palindrome
+/// within `max_iterations` steps.
+///
+/// # Examples
+///
+/// ```python
+/// import lychrel
+///
+/// candidates = lychrel.lychrel_candidates_in_range(1, 1000)
+/// assert 196 in candidates
+/// ```
+///
+/// # Errors
+///
+/// Returns a `ValueError` if `base` is less than 2.
 #[pyfunction]
-#[pyo3(signature = (number, max_iterations=None))]
-fn is_lychrel_candidate(number: BigUint, max_iterations: Option<usize>) -> bool {
-    find_lychrel_palindrome(number, max_iterations).is_err()
+#[pyo3(signature = (start, end, max_iterations=None, base=None, require_step=None))]
+fn lychrel_candidates_in_range(
+    start: u64,
+    end: u64,
+    max_iterations: Option<usize>,
+    base: Option<u32>,
+    require_step: Option<bool>,
+) -> PyResult<Vec<BigUint>> {
+    let max_iterations = max_iterations.unwrap_or(MAX_ITERATIONS);
+    let base = validate_base(base.unwrap_or(BASE))?;
+    let require_step = require_step.unwrap_or(true);
+
+    Ok((start..end)
+        .into_par_iter()
+        .filter_map(|number| {
+            let candidate = BigUint::from(number);
+
+            if reverse_and_add_search(candidate.clone(), max_iterations, base, require_step)
+                .is_none()
+            {
+                Some(candidate)
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+/// Count the suspected Lychrel numbers in an interval.
+///
+/// Equivalent to `len(lychrel_candidates_in_range(start, end, ...))`, but avoids
+/// materializing the candidates themselves, which matters for wide ranges such as
+/// Euler 55's "how many Lychrel numbers below ten-thousand".
+///
+/// # Arguments
+///
+/// * `start` - Start of the interval (inclusive)
+/// * `end` - End of the interval (exclusive)
+/// * `max_iterations` - Maximum iterations to try per candidate (default: 10000)
+/// * `base` - The number base to use (default: 10)
+/// * `require_step` - Whether a palindromic seed must still take one reverse-and-add
+///   step before being accepted (default: `True`, the Euler 55 convention, matching
+///   [`find_lychrel_palindrome`])
+///
+/// # Examples
+///
+/// ```python
+/// import lychrel
+///
+/// assert lychrel.count_lychrel_candidates(1, 10000) == 249
+/// ```
+///
+/// # Errors
+///
+/// Returns a `ValueError` if `base` is less than 2.
+#[pyfunction]
+#[pyo3(signature = (start, end, max_iterations=None, base=None, require_step=None))]
+fn count_lychrel_candidates(
+    start: u64,
+    end: u64,
+    max_iterations: Option<usize>,
+    base: Option<u32>,
+    require_step: Option<bool>,
+) -> PyResult<usize> {
+    let max_iterations = max_iterations.unwrap_or(MAX_ITERATIONS);
+    let base = validate_base(base.unwrap_or(BASE))?;
+    let require_step = require_step.unwrap_or(true);
+
+    Ok((start..end)
+        .into_par_iter()
+        .filter(|&number| {
+            reverse_and_add_search(BigUint::from(number), max_iterations, base, require_step)
+                .is_none()
+        })
+        .count())
+}
+
+/// Find the seed in an interval that takes the most steps to reach a palindrome.
+///
+/// Scans `[start, end)` in parallel with the same reverse-and-add core used by
+/// [`lychrel_candidates_in_range`], and keeps the seed with the highest iteration
+/// count, reproducing "delayed palindrome" record searches such as 89 (24 steps),
+/// 10677 (the first to exceed 50 steps) or the current world record holder
+/// 1186060307891929990 (261 steps).
+///
+/// # Arguments
+///
+/// * `start` - Start of the interval (inclusive)
+/// * `end` - End of the interval (exclusive)
+/// * `max_iterations` - Maximum iterations to try per candidate (default: 10000)
+/// * `base` - The number base to use (default: 10)
+/// * `require_step` - Whether a palindromic seed must still take one reverse-and-add
+///   step before being accepted (default: `True`, the Euler 55 convention, matching
+///   [`lychrel_candidates_in_range`] and [`count_lychrel_candidates`])
+///
+/// # Returns
+///
+/// A tuple `(seed, steps, palindrome)` for the most-delayed seed in the interval.
+/// When several seeds share the highest step count, the smallest of them is returned.
+///
+/// # Errors
+///
+/// Returns a `ValueError` if every seed in `[start, end)` fails to reach a
+/// palindrome within `max_iterations` steps, or if `base` is less than 2.
+///
+/// # Examples
+///
+/// ```python
+/// import lychrel
+///
+/// seed, steps, palindrome = lychrel.most_delayed_palindrome(1, 100)
+/// assert seed == 89
+/// assert steps == 24
+/// ```
+#[pyfunction]
+#[pyo3(signature = (start, end, max_iterations=None, base=None, require_step=None))]
+fn most_delayed_palindrome(
+    start: u64,
+    end: u64,
+    max_iterations: Option<usize>,
+    base: Option<u32>,
+    require_step: Option<bool>,
+) -> PyResult<(BigUint, usize, BigUint)> {
+    let max_iterations = max_iterations.unwrap_or(MAX_ITERATIONS);
+    let base = validate_base(base.unwrap_or(BASE))?;
+    let require_step = require_step.unwrap_or(true);
+
+    (start..end)
+        .into_par_iter()
+        .filter_map(|number| {
+            reverse_and_add_search(BigUint::from(number), max_iterations, base, require_step)
+                .map(|(palindrome, steps)| (number, steps, palindrome))
+        })
+        // On equal step counts, keep the smallest seed (e.g. 89 rather than 98)
+        .max_by_key(|&(seed, steps, _)| (steps, std::cmp::Reverse(seed)))
+        .map(|(seed, steps, palindrome)| (BigUint::from(seed), steps, palindrome))
+        .ok_or_else(|| {
+            PyValueError::new_err(
+                "No palindrome found within max_iterations for any seed in the range",
+            )
+        })
+}
+
+/// Return the full reverse-and-add trajectory from a seed to its first palindrome.
+///
+/// `find_lychrel_palindrome` only returns the final palindrome and a step count;
+/// this returns every intermediate term, e.g. `349 -> 1292 -> 4213 -> 7337`, so
+/// callers can inspect growth or plot digit-length without reimplementing the
+/// routine in Python.
+///
+/// # Arguments
+///
+/// * `number` - The starting number to test (any non-negative integer)
+/// * `max_iterations` - Maximum number of iterations to try before giving up (default: 10000)
+/// * `base` - The number base to use (default: 10)
+/// * `require_step` - Whether a palindromic seed must still take one reverse-and-add
+///   step before being accepted (default: `True`, the Euler 55 convention, matching
+///   [`find_lychrel_palindrome`])
+///
+/// # Returns
+///
+/// The path from `number` to the first palindrome, inclusive of both endpoints.
+///
+/// # Errors
+///
+/// Returns a `ValueError` if no palindrome is found within `max_iterations` steps,
+/// or if `base` is less than 2.
+///
+/// # Examples
+///
+/// ```python
+/// import lychrel
+///
+/// assert lychrel.reverse_and_add_sequence(349) == [349, 1292, 4213, 7337]
+/// ```
+#[pyfunction]
+#[pyo3(signature = (number, max_iterations=None, base=None, require_step=None))]
+fn reverse_and_add_sequence(
+    number: BigUint,
+    max_iterations: Option<usize>,
+    base: Option<u32>,
+    require_step: Option<bool>,
+) -> PyResult<Vec<BigUint>> {
+    let max_iterations = max_iterations.unwrap_or(MAX_ITERATIONS);
+    let base = validate_base(base.unwrap_or(BASE))?;
+    let require_step = require_step.unwrap_or(true);
+
+    reverse_and_add_path(number, max_iterations, base, require_step)
+        .ok_or_else(|| PyValueError::new_err("Maximum iterations reached"))
+}
+
+/// Lazily yield the reverse-and-add trajectory from a seed to its first palindrome.
+///
+/// Modeled on `CollatzIterator`: each `__next__` call computes and yields one more
+/// term, so callers that only want a prefix of a long sequence don't pay to
+/// materialize the whole thing the way `reverse_and_add_sequence` does. Iteration
+/// stops once a palindrome has been yielded. Like [`reverse_and_add_search`],
+/// `require_step` forces at least one reverse-and-add step before a palindromic
+/// seed is accepted as the terminal term.
+///
+/// # Errors
+///
+/// Raises a `ValueError` from `__next__` if `max_iterations` terms are yielded
+/// without reaching a palindrome, the same failure `find_lychrel_palindrome`
+/// reports - a caller iterating the whole sequence (e.g. via `list(...)`) is
+/// never silently handed a truncated, non-terminal trajectory. The constructor
+/// itself raises a `ValueError` if `base` is less than 2.
+#[pyclass]
+struct ReverseAddIterator {
+    next: BigUint,
+    base: u32,
+    step: usize,
+    max_iterations: usize,
+    require_step: bool,
+    stop: bool,
+}
+
+#[pymethods]
+impl ReverseAddIterator {
+    #[new]
+    #[pyo3(signature = (number, max_iterations=None, base=None, require_step=None))]
+    fn new(
+        number: BigUint,
+        max_iterations: Option<usize>,
+        base: Option<u32>,
+        require_step: Option<bool>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            next: number,
+            base: validate_base(base.unwrap_or(BASE))?,
+            step: 0,
+            max_iterations: max_iterations.unwrap_or(MAX_ITERATIONS),
+            require_step: require_step.unwrap_or(true),
+            stop: false,
+        })
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<BigUint>> {
+        if slf.stop {
+            return Ok(None);
+        }
+        if slf.step >= slf.max_iterations {
+            return Err(PyValueError::new_err("Maximum iterations reached"));
+        }
+
+        let current = slf.next.clone();
+        let skip_seed_check = slf.require_step && slf.step == 0;
+
+        match reverse_and_add_step(current.clone(), slf.base, skip_seed_check) {
+            ReverseAddStep::Accepted(palindrome) => {
+                slf.stop = true;
+                slf.step += 1;
+                Ok(Some(palindrome))
+            }
+            ReverseAddStep::Continue(value) => {
+                slf.next = value;
+                slf.step += 1;
+                Ok(Some(current))
+            }
+        }
+    }
 }
 
 /// Compute the nth term of a generalized Fibonacci sequence (Lucas sequence).
@@ -470,6 +909,11 @@ fn lychrel(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add("__version__", env!("CARGO_PKG_VERSION"))?;
     module.add_function(wrap_pyfunction!(is_lychrel_candidate, module)?)?;
     module.add_function(wrap_pyfunction!(find_lychrel_palindrome, module)?)?;
+    module.add_function(wrap_pyfunction!(lychrel_candidates_in_range, module)?)?;
+    module.add_function(wrap_pyfunction!(count_lychrel_candidates, module)?)?;
+    module.add_function(wrap_pyfunction!(most_delayed_palindrome, module)?)?;
+    module.add_function(wrap_pyfunction!(reverse_and_add_sequence, module)?)?;
+    module.add_class::<ReverseAddIterator>()?;
     module.add_function(wrap_pyfunction!(fibonacci, module)?)?;
     module.add_function(wrap_pyfunction!(look_and_say, module)?)?;
     module.add_function(wrap_pyfunction!(kaprekar, module)?)?;